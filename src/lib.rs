@@ -4,23 +4,49 @@ extern crate alloc;
 
 use sha3::{Digest, Keccak256};
 use alloc::string::String;
+use alloc::vec::Vec;
 use alloy_primitives::{Address, FixedBytes, U256};
 use alloy_sol_types::{sol, sol_data::{Address as SOLAddress, Bytes as SOLBytes, *}, SolType};
 // Import items from the SDK. The prelude contains common traits and macros.
-use stylus_sdk::{abi::Bytes, block, call::{call, Call}, evm, msg, prelude::*};
+use stylus_sdk::{abi::Bytes, block, call::{call, Call}, contract, evm, msg, prelude::*};
 
 // Define the types of the contract's storage.
-type TxIdHashType = (SOLAddress, Uint<256>, SOLBytes, SOLBytes, Uint<256>);
+type TxIdHashType = (SOLAddress, Uint<256>, SOLBytes, SOLBytes, Uint<256>, alloy_sol_types::sol_data::FixedBytes<32>);
+// Batch variant of `TxIdHashType`: parallel arrays of targets/values/funcs/datas plus one timestamp and predecessor.
+type BatchTxIdHashType = (Array<SOLAddress>, Array<Uint<256>>, Array<SOLBytes>, Array<SOLBytes>, Uint<256>, alloy_sol_types::sol_data::FixedBytes<32>);
+
+// The sentinel value stored in `queued` for an operation that has already been executed.
+// Real timestamps are always at least `min_delay` seconds after the block timestamp at queue
+// time, so `1` can never collide
+// with a genuinely scheduled timestamp.
+const DONE_TIMESTAMP: u64 = 1;
+
+// Derives a role identifier the same way OpenZeppelin's AccessControl does:
+// `keccak256("ROLE_NAME")`.
+fn role_hash(name: &str) -> FixedBytes<32> {
+    let mut hasher = Keccak256::new();
+    hasher.update(name.as_bytes());
+    let result = hasher.finalize();
+    FixedBytes::<32>::from_slice(&result)
+}
 
 sol!{
     error AlreadyInitialized();
-    error NotOwnerError();
+    error NotProposerError();
+    error NotExecutorError();
+    error NotAdminError();
     error AlreadyQueuedError(bytes32 txId);
     error TimestampNotInRangeError(uint256 blockTimestamp, uint256 timestamp);
     error NotQueuedError(bytes32 txId);
     error TimestampNotPassedError(uint256 blockTimestamp, uint256 timestamp);
     error TimestampExpiredError(uint256 blockTimestamp, uint256 expiresAt);
     error TxFailedError();
+    error BatchLengthMismatchError();
+    error ReturnDataMismatchError(bytes32 expectedHash, bytes32 actualHash);
+    error NotSelfError();
+    error MissingDependencyError(bytes32 predecessor);
+    error InvalidDelayConfigError(uint256 minDelay, uint256 maxDelay);
+    error AlreadyExecutedError(bytes32 txId);
 
     event Queue(
         bytes32 indexed txId,
@@ -28,7 +54,8 @@ sol!{
         uint256 value,
         string func,
         bytes data,
-        uint256 timestamp
+        uint256 timestamp,
+        bytes32 predecessor
     );
     event Execute(
         bytes32 indexed txId,
@@ -36,9 +63,45 @@ sol!{
         uint256 value,
         string func,
         bytes data,
-        uint256 timestamp
+        uint256 timestamp,
+        bytes32 predecessor,
+        bytes returnData
     );
     event Cancel(bytes32 indexed txId);
+
+    event QueueBatch(
+        bytes32 indexed txId,
+        address[] targets,
+        uint256[] values,
+        string[] funcs,
+        bytes[] datas,
+        uint256 timestamp,
+        bytes32 predecessor
+    );
+    event ExecuteBatch(
+        bytes32 indexed txId,
+        address[] targets,
+        uint256[] values,
+        string[] funcs,
+        bytes[] datas,
+        uint256 timestamp,
+        bytes32 predecessor
+    );
+    event CancelBatch(bytes32 indexed txId);
+
+    event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
+    event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
+
+    event DelayUpdated(uint256 minDelay, uint256 maxDelay, uint256 gracePeriod);
+
+    // Lifecycle of a queued operation, mirroring OpenZeppelin's TimelockController.
+    enum OperationState {
+        Unset,
+        Waiting,
+        Ready,
+        Expired,
+        Done
+    }
 }
 
 // Define persistent storage using the Solidity ABI.
@@ -47,8 +110,18 @@ sol_storage! {
     // Define the contract's storage.
     #[entrypoint]
     pub struct TimeLock {
-        address owner;
-        mapping(bytes32 => bool) queued;
+        bool initialized;
+        // Role membership, OpenZeppelin-AccessControl-style: role => account => granted.
+        // A grant to `address(0)` on EXECUTOR_ROLE makes that role open to everyone, so execution
+        // can be permissionless once the delay has elapsed; other roles ignore such a grant.
+        mapping(bytes32 => mapping(address => bool)) roles;
+        // Maps a txId to the timestamp it was scheduled for, or `DONE_TIMESTAMP` once executed.
+        // Absence (zero) means the operation is unset.
+        mapping(bytes32 => uint256) queued;
+        // Delay policy, governable via `updateDelay` instead of being fixed at deploy time.
+        uint256 min_delay;
+        uint256 max_delay;
+        uint256 grace_period;
     }
 }
 
@@ -57,8 +130,12 @@ sol_storage! {
 pub enum TimeLockError {
     // Error for when the contract is already initialized.
     AlreadyInitialized(AlreadyInitialized),
-    // Error for when the sender is not the owner
-    NotOwnerError(NotOwnerError),
+    // Error for when the sender does not hold PROPOSER_ROLE
+    NotProposerError(NotProposerError),
+    // Error for when the sender does not hold EXECUTOR_ROLE
+    NotExecutorError(NotExecutorError),
+    // Error for when the sender does not hold ADMIN_ROLE
+    NotAdminError(NotAdminError),
     // Error for when the transaction is already queued
     AlreadyQueuedError(AlreadyQueuedError),
     // Error for when the timestamp is not in the range
@@ -71,43 +148,159 @@ pub enum TimeLockError {
     TimestampExpiredError(TimestampExpiredError),
     // Error for when a transaction fails
     TxFailedError(TxFailedError),
+    // Error for when batch arrays don't all share the same length
+    BatchLengthMismatchError(BatchLengthMismatchError),
+    // Error for when the call's return data doesn't match the caller-supplied expected hash
+    ReturnDataMismatchError(ReturnDataMismatchError),
+    // Error for when a method restricted to the contract calling itself is invoked externally
+    NotSelfError(NotSelfError),
+    // Error for when an operation's predecessor has not yet executed
+    MissingDependencyError(MissingDependencyError),
+    // Error for when the new delay bounds don't satisfy minDelay <= maxDelay, or maxDelay is zero
+    InvalidDelayConfigError(InvalidDelayConfigError),
+    // Error for when cancelling an operation that has already executed
+    AlreadyExecutedError(AlreadyExecutedError),
 }
 
-// Minimum delay allowed for a transaction
-pub const MIN_DELAY: u64 = 10;
-// Maximum delay allowed for a transaction
-pub const MAX_DELAY: u64 = 1000;
-// Grace period after the maximum delay
-pub const GRACE_PERIOD: u64 = 1000;
+// Default minimum delay allowed for a transaction, seeded into storage on `initialize`.
+pub const DEFAULT_MIN_DELAY: u64 = 10;
+// Default maximum delay allowed for a transaction, seeded into storage on `initialize`.
+pub const DEFAULT_MAX_DELAY: u64 = 1000;
+// Default grace period after an operation's scheduled timestamp, seeded into storage on `initialize`.
+pub const DEFAULT_GRACE_PERIOD: u64 = 1000;
 
 // Marks `TimeLock` as a contract with the specified external methods
 #[public]
 impl TimeLock  {
 
     pub fn initialize(&mut self) -> Result<(), TimeLockError> {
-        if self.owner.get() != Address::default() {
+        if self.initialized.get() {
             return Err(TimeLockError::AlreadyInitialized(AlreadyInitialized{}))
         }
-        self.owner.set(msg::sender());
+        self.initialized.set(true);
+        // The caller of `initialize` starts out holding every role; they can grant/revoke from there.
+        self.grant_role_unchecked(role_hash("ADMIN_ROLE"), msg::sender());
+        self.grant_role_unchecked(role_hash("PROPOSER_ROLE"), msg::sender());
+        self.grant_role_unchecked(role_hash("EXECUTOR_ROLE"), msg::sender());
+        self.min_delay.set(U256::from(DEFAULT_MIN_DELAY));
+        self.max_delay.set(U256::from(DEFAULT_MAX_DELAY));
+        self.grace_period.set(U256::from(DEFAULT_GRACE_PERIOD));
+        Ok(())
+    }
+
+    pub fn min_delay(&self) -> U256 {
+        self.min_delay.get()
+    }
+
+    pub fn max_delay(&self) -> U256 {
+        self.max_delay.get()
+    }
+
+    pub fn grace_period(&self) -> U256 {
+        self.grace_period.get()
+    }
+
+    // Updates the timelock's own delay policy. Only the contract itself may call this,
+    // meaning it can only take effect by being queued and executed through the normal
+    // `queue` -> `execute` flow, under the delay it is changing.
+    pub fn update_delay(&mut self, min_delay: U256, max_delay: U256, grace_period: U256) -> Result<(), TimeLockError> {
+        if msg::sender() != contract::address() {
+            return Err(TimeLockError::NotSelfError(NotSelfError{}));
+        }
+        // An inverted min/max range would make queue/queue_batch's timestamp range check
+        // unsatisfiable, bricking scheduling until fixed by another self-call. A zero max_delay
+        // is rejected as well.
+        if max_delay.is_zero() || min_delay > max_delay {
+            return Err(TimeLockError::InvalidDelayConfigError(InvalidDelayConfigError{
+                minDelay: min_delay,
+                maxDelay: max_delay,
+            }));
+        }
+        self.min_delay.set(min_delay);
+        self.max_delay.set(max_delay);
+        self.grace_period.set(grace_period);
+        evm::log(DelayUpdated {
+            minDelay: min_delay,
+            maxDelay: max_delay,
+            gracePeriod: grace_period,
+        });
+        Ok(())
+    }
+
+    // Role identifier for the admin role, which manages membership of every role.
+    pub fn admin_role(&self) -> FixedBytes<32> {
+        role_hash("ADMIN_ROLE")
+    }
+
+    // Role identifier for the proposer role, which may call `queue`/`cancel` (and their
+    // batch equivalents).
+    pub fn proposer_role(&self) -> FixedBytes<32> {
+        role_hash("PROPOSER_ROLE")
+    }
+
+    // Role identifier for the executor role, which may call `execute` (and its batch
+    // equivalent). Granting this role to `address(0)` makes execution permissionless.
+    pub fn executor_role(&self) -> FixedBytes<32> {
+        role_hash("EXECUTOR_ROLE")
+    }
+
+    // Returns whether `account` holds `role`. Only EXECUTOR_ROLE honors a grant to
+    // `address(0)` as open to everyone, so execution can be made permissionless; ADMIN_ROLE
+    // and PROPOSER_ROLE always require an explicit grant to the caller.
+    pub fn has_role(&self, role: FixedBytes<32>, account: Address) -> bool {
+        if self.roles.getter(role).get(account) {
+            return true;
+        }
+        role == role_hash("EXECUTOR_ROLE") && self.roles.getter(role).get(Address::ZERO)
+    }
+
+    // Grants `role` to `account`. Callable only by an ADMIN_ROLE holder.
+    pub fn grant_role(&mut self, role: FixedBytes<32>, account: Address) -> Result<(), TimeLockError> {
+        if !self.has_role(role_hash("ADMIN_ROLE"), msg::sender()) {
+            return Err(TimeLockError::NotAdminError(NotAdminError{}));
+        }
+        self.grant_role_unchecked(role, account);
         Ok(())
     }
 
-    pub fn owner(&self) -> Address {
-        self.owner.get()
+    // Revokes `role` from `account`. Callable only by an ADMIN_ROLE holder.
+    pub fn revoke_role(&mut self, role: FixedBytes<32>, account: Address) -> Result<(), TimeLockError> {
+        if !self.has_role(role_hash("ADMIN_ROLE"), msg::sender()) {
+            return Err(TimeLockError::NotAdminError(NotAdminError{}));
+        }
+        self.roles.setter(role).setter(account).set(false);
+        evm::log(RoleRevoked {
+            role: role.into(),
+            account,
+            sender: msg::sender(),
+        });
+        Ok(())
+    }
+
+    // Grants `role` to `account` without an admin check, for internal use by `initialize`
+    // and `grant_role`.
+    fn grant_role_unchecked(&mut self, role: FixedBytes<32>, account: Address) {
+        self.roles.setter(role).setter(account).set(true);
+        evm::log(RoleGranted {
+            role: role.into(),
+            account,
+            sender: msg::sender(),
+        });
     }
 
     // Function to generate a transaction ID
     pub fn get_tx_id(
-        &self, 
+        &self,
         target: Address, // Target address for the transaction
         value: U256, // Value to be transferred
         func: String, // Function name to be called
         data: Bytes, // Data to be passed to the function
         timestamp: U256, // Timestamp for the transaction
+        predecessor: FixedBytes<32>, // txId that must be Done before this one is runnable, or zero
     ) -> FixedBytes<32>{
-        
+
         // Package the transaction data
-        let tx_hash_data = (target, value, func, data, timestamp);
+        let tx_hash_data = (target, value, func, data, timestamp, predecessor);
         // Encode the transaction data using ABI encoding
         let tx_hash_bytes = TxIdHashType::abi_encode_sequence(&tx_hash_data);
         // Initialize a new Keccak256 hasher
@@ -123,6 +316,43 @@ impl TimeLock  {
         alloy_primitives::FixedBytes::<32>::from_slice(&result_vec)
     }
 
+    // Function to generate a batch transaction ID, hashing the parallel arrays plus the
+    // shared timestamp and predecessor so the whole batch resolves to a single txId.
+    pub fn get_batch_tx_id(
+        &self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        funcs: Vec<String>,
+        datas: Vec<Bytes>,
+        timestamp: U256,
+        predecessor: FixedBytes<32>,
+    ) -> FixedBytes<32> {
+        let datas: Vec<Vec<u8>> = datas.into_iter().map(|d| d.to_vec()).collect();
+        let tx_hash_data = (targets, values, funcs, datas, timestamp, predecessor);
+        let tx_hash_bytes = BatchTxIdHashType::abi_encode_sequence(&tx_hash_data);
+        let mut hasher = Keccak256::new();
+        hasher.update(tx_hash_bytes);
+        let result = hasher.finalize();
+        let result_vec = result.to_vec();
+        alloy_primitives::FixedBytes::<32>::from_slice(&result_vec)
+    }
+
+    // Returns the lifecycle state of an operation (single or batch) identified by `txId`.
+    pub fn get_operation_state(&self, tx_id: FixedBytes<32>) -> OperationState {
+        let timestamp = self.queued.get(tx_id);
+        if timestamp.is_zero() {
+            OperationState::Unset
+        } else if timestamp == U256::from(DONE_TIMESTAMP) {
+            OperationState::Done
+        } else if U256::from(block::timestamp()) < timestamp {
+            OperationState::Waiting
+        } else if U256::from(block::timestamp()) > timestamp + self.grace_period.get() {
+            OperationState::Expired
+        } else {
+            OperationState::Ready
+        }
+    }
+
     // The `deposit` method is payable, so it can receive funds.
     #[payable]
     pub fn deposit(&self) {
@@ -136,30 +366,30 @@ impl TimeLock  {
         func: String, // Function name to be called
         data: Bytes, // Data to be passed to the function
         timestamp: U256, // Timestamp for the transaction
+        predecessor: FixedBytes<32>, // txId that must be Done before this one is runnable, or zero
     ) -> Result<(), TimeLockError> {
-        // Check if the caller is the owner of the contract
-        if self.owner.get() != msg::sender() {
-            // If not, return an error indicating the caller is not the owner
-            return Err(TimeLockError::NotOwnerError(NotOwnerError{}));
+        // Check if the caller holds PROPOSER_ROLE
+        if !self.has_role(role_hash("PROPOSER_ROLE"), msg::sender()) {
+            return Err(TimeLockError::NotProposerError(NotProposerError{}));
         };
-        
+
         // Calculate a transaction ID using the provided parameters
-        let tx_id = self.get_tx_id(target, value, func.clone(), data.clone(), timestamp);
+        let tx_id = self.get_tx_id(target, value, func.clone(), data.clone(), timestamp, predecessor);
         // Check if the transaction is already queued
-        if self.queued.get(tx_id) {
+        if !self.queued.get(tx_id).is_zero() {
             return Err(TimeLockError::AlreadyQueuedError(AlreadyQueuedError{txId: tx_id.into()}));
         }
 
         // Check if the provided timestamp is within the allowed range
-        if timestamp < U256::from(block::timestamp()) + U256::from(MIN_DELAY)
-            || timestamp > U256::from(block::timestamp()) + U256::from(MAX_DELAY)
+        if timestamp < U256::from(block::timestamp()) + self.min_delay.get()
+            || timestamp > U256::from(block::timestamp()) + self.max_delay.get()
         {
             return Err(TimeLockError::TimestampNotInRangeError(TimestampNotInRangeError{blockTimestamp: U256::from(block::timestamp()),timestamp: timestamp}));
         }
 
-        // Set the transaction as queued in the contract's state
+        // Record the operation's scheduled timestamp in the contract's state
         let mut queue_id = self.queued.setter(tx_id);
-        queue_id.set(true);
+        queue_id.set(timestamp);
         // Log the Queue event
         evm::log(Queue {
             txId: tx_id.into(),
@@ -168,12 +398,14 @@ impl TimeLock  {
             func: func,
             data: data.to_vec().into(),
             timestamp: timestamp,
+            predecessor: predecessor.into(),
         });
         // If all checks pass and the transaction is successfully queued, return Ok
         Ok(())
     }
 
-    // Function to execute a queued transaction
+    // Function to execute a queued transaction. Returns the callee's return data so callers
+    // can inspect the result of the call.
     pub fn execute(
         &mut self,
         target: Address, // Target address for the transaction
@@ -181,20 +413,26 @@ impl TimeLock  {
         func: String, // Function name to be called
         data: Bytes, // Data to be passed to the function
         timestamp: U256, // Timestamp for the transaction
-    ) -> Result<(), TimeLockError> {
-        // Check if the caller is the owner of the contract
-        if self.owner.get() != msg::sender() {
-            // If not, return an error indicating the caller is not the owner
-            return Err(TimeLockError::NotOwnerError(NotOwnerError{}));
+        predecessor: FixedBytes<32>, // txId that must be Done before this one is runnable, or zero
+        expected_return_hash: FixedBytes<32>, // keccak256 the return data must match; zero disables the check
+    ) -> Result<Bytes, TimeLockError> {
+        // Check if the caller holds EXECUTOR_ROLE
+        if !self.has_role(role_hash("EXECUTOR_ROLE"), msg::sender()) {
+            return Err(TimeLockError::NotExecutorError(NotExecutorError{}));
         };
-        
+
         // Calculate a transaction ID using the provided parameters
-        let tx_id = self.get_tx_id(target, value, func.clone(), data.clone(), timestamp);
+        let tx_id = self.get_tx_id(target, value, func.clone(), data.clone(), timestamp, predecessor);
         // Check if the transaction is not queued
-        if !self.queued.get(tx_id) {
+        if self.queued.get(tx_id).is_zero() {
             return Err(TimeLockError::NotQueuedError(NotQueuedError{txId: tx_id.into()}));
         }
-        
+
+        // Check if the predecessor (if any) has already executed
+        if !predecessor.is_zero() && self.get_operation_state(predecessor) != OperationState::Done {
+            return Err(TimeLockError::MissingDependencyError(MissingDependencyError{predecessor: predecessor.into()}));
+        }
+
         // ----|-------------------|-------
         //  timestamp    timestamp + grace period
 
@@ -202,19 +440,19 @@ impl TimeLock  {
         if U256::from(block::timestamp()) < timestamp {
             return Err(TimeLockError::TimestampNotPassedError(TimestampNotPassedError{blockTimestamp: U256::from(block::timestamp()), timestamp: timestamp}));
         }
-        
+
         // Check if the timestamp has expired
-        if U256::from(block::timestamp()) > timestamp + U256::from(GRACE_PERIOD) {
-            return Err(TimeLockError::TimestampExpiredError(TimestampExpiredError{blockTimestamp: U256::from(block::timestamp()), expiresAt: timestamp + U256::from(GRACE_PERIOD)}));
+        if U256::from(block::timestamp()) > timestamp + self.grace_period.get() {
+            return Err(TimeLockError::TimestampExpiredError(TimestampExpiredError{blockTimestamp: U256::from(block::timestamp()), expiresAt: timestamp + self.grace_period.get()}));
         }
-        
-        // Set the transaction as not queued in the contract's state
+
+        // Mark the operation as done in the contract's state
         let mut queue_id = self.queued.setter(tx_id);
-        queue_id.set(false);
+        queue_id.set(U256::from(DONE_TIMESTAMP));
 
         // Clone the data variable to ensure its lifetime is long enough
         // let cloned_data: Vec<u8> = data.clone().into();
-        
+
         // Prepare calldata
         let mut hasher = Keccak256::new();
         hasher.update(func.as_bytes());
@@ -222,11 +460,25 @@ impl TimeLock  {
         let hashed_function_selector = hasher.finalize();
         // Combine function selector and input data
         let calldata = [&hashed_function_selector[..4], &data].concat();
-        
+
         // Call the target contract with the provided parameters
         match call(Call::new_in(self).value(value), target, &calldata) {
-            // Log the transaction execution if successful
-            Ok(_) => {
+            // Capture the return data if the call succeeds
+            Ok(return_data) => {
+                // Guard against the "silent failure" class of bug where the call returns
+                // successfully but the encoded result isn't what the caller expected.
+                if !expected_return_hash.is_zero() {
+                    let mut hasher = Keccak256::new();
+                    hasher.update(&return_data);
+                    let actual_hash = FixedBytes::<32>::from_slice(&hasher.finalize());
+                    if actual_hash != expected_return_hash {
+                        return Err(TimeLockError::ReturnDataMismatchError(ReturnDataMismatchError{
+                            expectedHash: expected_return_hash.into(),
+                            actualHash: actual_hash.into(),
+                        }));
+                    }
+                }
+
                 evm::log(Execute {
                     txId: tx_id.into(),
                     target,
@@ -234,8 +486,10 @@ impl TimeLock  {
                     func: func,
                     data: data.to_vec().into(),
                     timestamp: timestamp,
+                    predecessor: predecessor.into(),
+                    returnData: return_data.clone().into(),
                 });
-                Ok(())
+                Ok(return_data.into())
             },
             // Return an error if the transaction fails
             Err(_) => Err(TimeLockError::TxFailedError(TxFailedError{})),
@@ -244,8 +498,8 @@ impl TimeLock  {
 
     pub fn executeV2(&mut self, target: Address) -> Result<(), TimeLockError> {
        call(
-            Call::new_in(self).value(U256::from(1)), 
-            target, 
+            Call::new_in(self).value(U256::from(1)),
+            target,
             &[]
         );
         Ok(())
@@ -258,23 +512,30 @@ impl TimeLock  {
         func: String,
         data: Bytes,
         timestamp: U256,
+        predecessor: FixedBytes<32>,
     ) -> Result<(), TimeLockError> {
-        // Check if the caller is the owner of the contract
-        if self.owner.get() != msg::sender() {
-            // If not, return an error indicating the caller is not the owner
-            return Err(TimeLockError::NotOwnerError(NotOwnerError{}));
+        // Check if the caller holds PROPOSER_ROLE
+        if !self.has_role(role_hash("PROPOSER_ROLE"), msg::sender()) {
+            return Err(TimeLockError::NotProposerError(NotProposerError{}));
         };
 
         // Calculate a transaction ID using the provided parameters
-        let tx_id = self.get_tx_id(target, value, func, data, timestamp);
+        let tx_id = self.get_tx_id(target, value, func, data, timestamp, predecessor);
         // Check if the transaction is not queued
-        if !self.queued.get(tx_id) {
+        if self.queued.get(tx_id).is_zero() {
             return Err(TimeLockError::NotQueuedError(NotQueuedError{txId: tx_id.into()}));
         }
 
-        // Set the transaction as not queued in the contract's state
+        // An already-executed operation can't be cancelled: resetting it back to `Unset`
+        // would erase its execution history and let it be re-queued/re-executed, and would
+        // break any dependent whose `predecessor` points at it.
+        if self.get_operation_state(tx_id) == OperationState::Done {
+            return Err(TimeLockError::AlreadyExecutedError(AlreadyExecutedError{txId: tx_id.into()}));
+        }
+
+        // Clear the operation from the contract's state
         let mut queue_id = self.queued.setter(tx_id);
-        queue_id.set(false);
+        queue_id.set(U256::ZERO);
 
         // Log the transaction cancellation
         evm::log(Cancel {
@@ -285,5 +546,421 @@ impl TimeLock  {
         Ok(())
     }
 
-    
+    // Function to queue a batch of transactions, all resolving to a single txId.
+    pub fn queue_batch(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        funcs: Vec<String>,
+        datas: Vec<Bytes>,
+        timestamp: U256,
+        predecessor: FixedBytes<32>, // txId that must be Done before this batch is runnable, or zero
+    ) -> Result<(), TimeLockError> {
+        // Check if the caller holds PROPOSER_ROLE
+        if !self.has_role(role_hash("PROPOSER_ROLE"), msg::sender()) {
+            return Err(TimeLockError::NotProposerError(NotProposerError{}));
+        };
+
+        // All parallel arrays must be the same length
+        if targets.len() != values.len() || targets.len() != funcs.len() || targets.len() != datas.len() {
+            return Err(TimeLockError::BatchLengthMismatchError(BatchLengthMismatchError{}));
+        }
+
+        // Calculate a single transaction ID for the whole batch
+        let tx_id = self.get_batch_tx_id(targets.clone(), values.clone(), funcs.clone(), datas.clone(), timestamp, predecessor);
+        // Check if the batch is already queued
+        if !self.queued.get(tx_id).is_zero() {
+            return Err(TimeLockError::AlreadyQueuedError(AlreadyQueuedError{txId: tx_id.into()}));
+        }
+
+        // Check if the provided timestamp is within the allowed range
+        if timestamp < U256::from(block::timestamp()) + self.min_delay.get()
+            || timestamp > U256::from(block::timestamp()) + self.max_delay.get()
+        {
+            return Err(TimeLockError::TimestampNotInRangeError(TimestampNotInRangeError{blockTimestamp: U256::from(block::timestamp()),timestamp: timestamp}));
+        }
+
+        // Record the batch's scheduled timestamp in the contract's state
+        let mut queue_id = self.queued.setter(tx_id);
+        queue_id.set(timestamp);
+        // Log the QueueBatch event
+        evm::log(QueueBatch {
+            txId: tx_id.into(),
+            targets,
+            values,
+            funcs,
+            datas: datas.into_iter().map(|d| d.to_vec().into()).collect(),
+            timestamp,
+            predecessor: predecessor.into(),
+        });
+        Ok(())
+    }
+
+    // Function to atomically execute a queued batch of transactions. If any sub-call fails,
+    // the whole call reverts, so either every transaction in the batch lands or none of them do.
+    pub fn execute_batch(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        funcs: Vec<String>,
+        datas: Vec<Bytes>,
+        timestamp: U256,
+        predecessor: FixedBytes<32>, // txId that must be Done before this batch is runnable, or zero
+    ) -> Result<(), TimeLockError> {
+        // Check if the caller holds EXECUTOR_ROLE
+        if !self.has_role(role_hash("EXECUTOR_ROLE"), msg::sender()) {
+            return Err(TimeLockError::NotExecutorError(NotExecutorError{}));
+        };
+
+        if targets.len() != values.len() || targets.len() != funcs.len() || targets.len() != datas.len() {
+            return Err(TimeLockError::BatchLengthMismatchError(BatchLengthMismatchError{}));
+        }
+
+        // Calculate the batch transaction ID
+        let tx_id = self.get_batch_tx_id(targets.clone(), values.clone(), funcs.clone(), datas.clone(), timestamp, predecessor);
+        // Check if the batch is not queued
+        if self.queued.get(tx_id).is_zero() {
+            return Err(TimeLockError::NotQueuedError(NotQueuedError{txId: tx_id.into()}));
+        }
+
+        // Check if the predecessor (if any) has already executed
+        if !predecessor.is_zero() && self.get_operation_state(predecessor) != OperationState::Done {
+            return Err(TimeLockError::MissingDependencyError(MissingDependencyError{predecessor: predecessor.into()}));
+        }
+
+        // Check if the timestamp has passed
+        if U256::from(block::timestamp()) < timestamp {
+            return Err(TimeLockError::TimestampNotPassedError(TimestampNotPassedError{blockTimestamp: U256::from(block::timestamp()), timestamp: timestamp}));
+        }
+
+        // Check if the timestamp has expired
+        if U256::from(block::timestamp()) > timestamp + self.grace_period.get() {
+            return Err(TimeLockError::TimestampExpiredError(TimestampExpiredError{blockTimestamp: U256::from(block::timestamp()), expiresAt: timestamp + self.grace_period.get()}));
+        }
+
+        // Mark the batch as done before executing, same ordering as the single-op `execute`
+        let mut queue_id = self.queued.setter(tx_id);
+        queue_id.set(U256::from(DONE_TIMESTAMP));
+
+        // Execute every call in order; any failure reverts the entire batch
+        for i in 0..targets.len() {
+            let mut hasher = Keccak256::new();
+            hasher.update(funcs[i].as_bytes());
+            let hashed_function_selector = hasher.finalize();
+            let calldata = [&hashed_function_selector[..4], &datas[i]].concat();
+
+            match call(Call::new_in(self).value(values[i]), targets[i], &calldata) {
+                Ok(_) => {}
+                Err(_) => return Err(TimeLockError::TxFailedError(TxFailedError{})),
+            }
+        }
+
+        evm::log(ExecuteBatch {
+            txId: tx_id.into(),
+            targets,
+            values,
+            funcs,
+            datas: datas.into_iter().map(|d| d.to_vec().into()).collect(),
+            timestamp,
+            predecessor: predecessor.into(),
+        });
+        Ok(())
+    }
+
+    // Function to cancel a queued batch of transactions
+    pub fn cancel_batch(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        funcs: Vec<String>,
+        datas: Vec<Bytes>,
+        timestamp: U256,
+        predecessor: FixedBytes<32>,
+    ) -> Result<(), TimeLockError> {
+        // Check if the caller holds PROPOSER_ROLE
+        if !self.has_role(role_hash("PROPOSER_ROLE"), msg::sender()) {
+            return Err(TimeLockError::NotProposerError(NotProposerError{}));
+        };
+
+        if targets.len() != values.len() || targets.len() != funcs.len() || targets.len() != datas.len() {
+            return Err(TimeLockError::BatchLengthMismatchError(BatchLengthMismatchError{}));
+        }
+
+        let tx_id = self.get_batch_tx_id(targets, values, funcs, datas, timestamp, predecessor);
+        if self.queued.get(tx_id).is_zero() {
+            return Err(TimeLockError::NotQueuedError(NotQueuedError{txId: tx_id.into()}));
+        }
+
+        // An already-executed batch can't be cancelled; see `cancel` for why.
+        if self.get_operation_state(tx_id) == OperationState::Done {
+            return Err(TimeLockError::AlreadyExecutedError(AlreadyExecutedError{txId: tx_id.into()}));
+        }
+
+        // Clear the batch from the contract's state
+        let mut queue_id = self.queued.setter(tx_id);
+        queue_id.set(U256::ZERO);
+
+        evm::log(CancelBatch {
+            txId: tx_id.into(),
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use stylus_sdk::testing::*;
+
+    fn addr(byte: u8) -> Address {
+        Address::from_slice(&[byte; 20])
+    }
+
+    #[test]
+    fn only_admin_can_grant_and_revoke_roles() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+        let other = addr(2);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+
+        // A non-admin cannot grant or revoke roles.
+        vm.set_sender(other);
+        assert!(matches!(
+            contract.grant_role(contract.proposer_role(), other),
+            Err(TimeLockError::NotAdminError(_))
+        ));
+
+        // The admin can.
+        vm.set_sender(admin);
+        contract.grant_role(contract.proposer_role(), other).unwrap();
+        assert!(contract.has_role(contract.proposer_role(), other));
+
+        contract.revoke_role(contract.proposer_role(), other).unwrap();
+        assert!(!contract.has_role(contract.proposer_role(), other));
+    }
+
+    #[test]
+    fn open_role_wildcard_is_scoped_to_executor_role() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+        let anyone = addr(2);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+
+        // Granting EXECUTOR_ROLE to the zero address makes execution permissionless.
+        contract.grant_role(contract.executor_role(), Address::ZERO).unwrap();
+        assert!(contract.has_role(contract.executor_role(), anyone));
+
+        // The same wildcard grant on ADMIN_ROLE/PROPOSER_ROLE must not open those roles up.
+        contract.grant_role(contract.admin_role(), Address::ZERO).unwrap();
+        contract.grant_role(contract.proposer_role(), Address::ZERO).unwrap();
+        assert!(!contract.has_role(contract.admin_role(), anyone));
+        assert!(!contract.has_role(contract.proposer_role(), anyone));
+    }
+
+    #[test]
+    fn update_delay_requires_self_call() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+
+        assert!(matches!(
+            contract.update_delay(U256::from(20), U256::from(2000), U256::from(2000)),
+            Err(TimeLockError::NotSelfError(_))
+        ));
+
+        vm.set_sender(contract::address());
+        contract.update_delay(U256::from(20), U256::from(2000), U256::from(2000)).unwrap();
+        assert_eq!(contract.min_delay(), U256::from(20));
+    }
+
+    #[test]
+    fn update_delay_rejects_invalid_bounds() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+        vm.set_sender(contract::address());
+
+        // An inverted min/max range would make queue's timestamp check unsatisfiable.
+        assert!(matches!(
+            contract.update_delay(U256::from(2000), U256::from(20), U256::from(2000)),
+            Err(TimeLockError::InvalidDelayConfigError(_))
+        ));
+
+        // A zero max_delay is rejected too.
+        assert!(matches!(
+            contract.update_delay(U256::from(0), U256::from(0), U256::from(2000)),
+            Err(TimeLockError::InvalidDelayConfigError(_))
+        ));
+
+        // The prior valid configuration from `initialize` must be untouched by the rejections.
+        assert_eq!(contract.min_delay(), U256::from(DEFAULT_MIN_DELAY));
+        assert_eq!(contract.max_delay(), U256::from(DEFAULT_MAX_DELAY));
+    }
+
+    #[test]
+    fn operation_state_follows_timestamp_and_grace_period() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+        vm.set_block_timestamp(1_000);
+
+        let target = addr(3);
+        let timestamp = U256::from(1_000 + DEFAULT_MIN_DELAY);
+        contract
+            .queue(target, U256::ZERO, "noop()".into(), Bytes::from(Vec::new()), timestamp, FixedBytes::<32>::ZERO)
+            .unwrap();
+        let tx_id = contract.get_tx_id(target, U256::ZERO, "noop()".into(), Bytes::from(Vec::new()), timestamp, FixedBytes::<32>::ZERO);
+
+        assert_eq!(contract.get_operation_state(tx_id), OperationState::Waiting);
+
+        vm.set_block_timestamp(1_000 + DEFAULT_MIN_DELAY);
+        assert_eq!(contract.get_operation_state(tx_id), OperationState::Ready);
+
+        vm.set_block_timestamp(1_000 + DEFAULT_MIN_DELAY + DEFAULT_GRACE_PERIOD + 1);
+        assert_eq!(contract.get_operation_state(tx_id), OperationState::Expired);
+    }
+
+    #[test]
+    fn execute_enforces_missing_dependency() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+        vm.set_block_timestamp(1_000);
+
+        let target = addr(3);
+        let timestamp = U256::from(1_000 + DEFAULT_MIN_DELAY);
+        // A predecessor txId that was never queued, so it is forever `Unset`.
+        let predecessor = FixedBytes::<32>::from_slice(&[9u8; 32]);
+        contract
+            .queue(target, U256::ZERO, "noop()".into(), Bytes::from(Vec::new()), timestamp, predecessor)
+            .unwrap();
+
+        vm.set_block_timestamp(1_000 + DEFAULT_MIN_DELAY);
+        assert!(matches!(
+            contract.execute(target, U256::ZERO, "noop()".into(), Bytes::from(Vec::new()), timestamp, predecessor, FixedBytes::<32>::ZERO),
+            Err(TimeLockError::MissingDependencyError(_))
+        ));
+    }
+
+    #[test]
+    fn batch_methods_reject_mismatched_lengths_and_missing_dependency() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+        vm.set_block_timestamp(1_000);
+
+        let targets = Vec::from([addr(3), addr(4)]);
+        let values = Vec::from([U256::ZERO]);
+        let funcs: Vec<String> = Vec::from(["noop()".into(), "noop()".into()]);
+        let datas = Vec::from([Bytes::from(Vec::new()), Bytes::from(Vec::new())]);
+
+        assert!(matches!(
+            contract.queue_batch(targets.clone(), values.clone(), funcs.clone(), datas.clone(), U256::from(2_000), FixedBytes::<32>::ZERO),
+            Err(TimeLockError::BatchLengthMismatchError(_))
+        ));
+        assert!(matches!(
+            contract.execute_batch(targets.clone(), values.clone(), funcs.clone(), datas.clone(), U256::from(2_000), FixedBytes::<32>::ZERO),
+            Err(TimeLockError::BatchLengthMismatchError(_))
+        ));
+        assert!(matches!(
+            contract.cancel_batch(targets.clone(), values.clone(), funcs.clone(), datas.clone(), U256::from(2_000), FixedBytes::<32>::ZERO),
+            Err(TimeLockError::BatchLengthMismatchError(_))
+        ));
+
+        // Queue a well-formed batch that depends on a predecessor that never completes.
+        let values = Vec::from([U256::ZERO, U256::ZERO]);
+        let timestamp = U256::from(1_000 + DEFAULT_MIN_DELAY);
+        let predecessor = FixedBytes::<32>::from_slice(&[9u8; 32]);
+        contract
+            .queue_batch(targets.clone(), values.clone(), funcs.clone(), datas.clone(), timestamp, predecessor)
+            .unwrap();
+
+        vm.set_block_timestamp(1_000 + DEFAULT_MIN_DELAY);
+        assert!(matches!(
+            contract.execute_batch(targets, values, funcs, datas, timestamp, predecessor),
+            Err(TimeLockError::MissingDependencyError(_))
+        ));
+    }
+
+    #[test]
+    fn cancel_rejects_an_already_executed_operation() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+        vm.set_block_timestamp(1_000);
+
+        let target = addr(3);
+        let timestamp = U256::from(1_000 + DEFAULT_MIN_DELAY);
+        contract
+            .queue(target, U256::ZERO, "noop()".into(), Bytes::from(Vec::new()), timestamp, FixedBytes::<32>::ZERO)
+            .unwrap();
+
+        vm.set_block_timestamp(1_000 + DEFAULT_MIN_DELAY);
+        contract
+            .execute(target, U256::ZERO, "noop()".into(), Bytes::from(Vec::new()), timestamp, FixedBytes::<32>::ZERO, FixedBytes::<32>::ZERO)
+            .unwrap();
+
+        // Cancelling a `Done` operation must not revert it back to `Unset`: it would erase
+        // execution history and let it be re-queued/re-executed.
+        assert!(matches!(
+            contract.cancel(target, U256::ZERO, "noop()".into(), Bytes::from(Vec::new()), timestamp, FixedBytes::<32>::ZERO),
+            Err(TimeLockError::AlreadyExecutedError(_))
+        ));
+    }
+
+    #[test]
+    fn cancel_batch_rejects_an_already_executed_batch() {
+        let vm = TestVM::default();
+        let mut contract = TimeLock::from(&vm);
+        let admin = addr(1);
+
+        vm.set_sender(admin);
+        contract.initialize().unwrap();
+        vm.set_block_timestamp(1_000);
+
+        let targets = Vec::from([addr(3), addr(4)]);
+        let values = Vec::from([U256::ZERO, U256::ZERO]);
+        let funcs: Vec<String> = Vec::from(["noop()".into(), "noop()".into()]);
+        let datas = Vec::from([Bytes::from(Vec::new()), Bytes::from(Vec::new())]);
+        let timestamp = U256::from(1_000 + DEFAULT_MIN_DELAY);
+
+        contract
+            .queue_batch(targets.clone(), values.clone(), funcs.clone(), datas.clone(), timestamp, FixedBytes::<32>::ZERO)
+            .unwrap();
+
+        vm.set_block_timestamp(1_000 + DEFAULT_MIN_DELAY);
+        contract
+            .execute_batch(targets.clone(), values.clone(), funcs.clone(), datas.clone(), timestamp, FixedBytes::<32>::ZERO)
+            .unwrap();
+
+        assert!(matches!(
+            contract.cancel_batch(targets, values, funcs, datas, timestamp, FixedBytes::<32>::ZERO),
+            Err(TimeLockError::AlreadyExecutedError(_))
+        ));
+    }
 }